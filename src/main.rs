@@ -1,24 +1,125 @@
-use iced::widget::{button, column, text, Container};
-use iced::{Element, Sandbox, Settings, Theme};
-use sysinfo::{System, SystemExt, CpuExt, DiskExt, NetworkExt};
+use iced::widget::canvas::{self, Canvas, Path, Stroke};
+use iced::widget::{button, column, row, text, text_input, Container};
+use iced::{Application, Color, Command, Element, Length, Point, Rectangle, Renderer, Settings, Subscription, Theme};
+use sysinfo::{System, SystemExt, ComponentExt, CpuExt, DiskExt, NetworkExt, PidExt, ProcessExt, UserExt};
+use regex::Regex;
+use serde::Serialize;
+use std::collections::VecDeque;
 use std::fs::OpenOptions;
 use std::io::{Error, Write};
-use chrono::{Local, Timelike};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use chrono::{DateTime, Local, TimeZone, Timelike};
+
+/// How long a sample stays in a history buffer before being evicted.
+const HISTORY_RETENTION: Duration = Duration::from_secs(60);
+
+/// Selectable auto-refresh intervals, from snappy to battery-friendly.
+const REFRESH_INTERVALS_MS: [u64; 4] = [250, 1_000, 5_000, 10_000];
+const DEFAULT_REFRESH_INTERVAL_MS: u64 = 1_000;
 
 pub fn main() -> iced::Result {
     Task::run(Settings::default())
 }
 
+/// The application. The `System` lives here (outside `State`) so it survives every
+/// `Loading`/`Loaded` transition and is never rebuilt, keeping its refresh deltas meaningful.
+struct Task {
+    system: Arc<Mutex<System>>,
+    state: State,
+}
+
 #[derive(Default)]
-enum Task {
+enum State {
     #[default]
     Loading,
     Loaded {
-        information: SystemInformation,
-        show_cpu_usage: bool, 
+        information: Box<SystemInformation>,
+        show_cpu_usage: bool,
+        process_sorting: ProcessSorting,
+        history: Box<MetricHistory>,
+        refresh_interval_ms: u64,
+        search_query: String,
+        use_regex: bool,
+        search_regex: Option<Regex>,
+        host_info: Box<HostInfo>,
     },
 }
 
+#[derive(Default)]
+struct MetricHistory {
+    cpu_total: VecDeque<(Instant, f32)>,
+    memory_percent: VecDeque<(Instant, f32)>,
+    net_rx: VecDeque<(Instant, f32)>,
+    net_tx: VecDeque<(Instant, f32)>,
+}
+
+impl MetricHistory {
+    fn push(&mut self, now: Instant, cpu_total: f32, memory_percent: f32, net_rx: f32, net_tx: f32) {
+        self.cpu_total.push_back((now, cpu_total));
+        self.memory_percent.push_back((now, memory_percent));
+        self.net_rx.push_back((now, net_rx));
+        self.net_tx.push_back((now, net_tx));
+
+        for series in [
+            &mut self.cpu_total,
+            &mut self.memory_percent,
+            &mut self.net_rx,
+            &mut self.net_tx,
+        ] {
+            let cutoff = now.checked_sub(HISTORY_RETENTION).unwrap_or(now);
+            while series.front().is_some_and(|(t, _)| *t < cutoff) {
+                series.pop_front();
+            }
+        }
+    }
+}
+
+struct LineChart<'a> {
+    series: &'a VecDeque<(Instant, f32)>,
+}
+
+impl<'a> canvas::Program<Message> for LineChart<'a> {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: iced::mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+
+        if self.series.len() >= 2 {
+            let min = self.series.iter().map(|(_, v)| *v).fold(f32::INFINITY, f32::min);
+            let max = self.series.iter().map(|(_, v)| *v).fold(f32::NEG_INFINITY, f32::max);
+            let span = (max - min).max(f32::EPSILON);
+            let newest = self.series.back().unwrap().0;
+
+            let points: Vec<Point> = self.series.iter().map(|(t, v)| {
+                let age = newest.duration_since(*t).as_secs_f32();
+                let x = bounds.width * (1.0 - age / HISTORY_RETENTION.as_secs_f32().max(f32::EPSILON));
+                let y = bounds.height * (1.0 - (*v - min) / span);
+                Point::new(x, y)
+            }).collect();
+
+            let path = Path::new(|builder| {
+                builder.move_to(points[0]);
+                for point in &points[1..] {
+                    builder.line_to(*point);
+                }
+            });
+
+            frame.stroke(&path, Stroke::default().with_width(2.0));
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
 struct SystemInformation {
     cpu_usages: Vec<f32>,
     used_memory: u64,
@@ -27,21 +128,212 @@ struct SystemInformation {
     total_swap: u64,
     disks: Vec<(String, u64, u64)>,
     networks: Vec<(String, u64, u64)>,
+    processes: Vec<ProcessRow>,
+    components: Vec<(String, f32, Option<f32>)>,
+}
+
+#[derive(Clone, Debug)]
+struct HostInfo {
+    os_name: String,
+    os_version: String,
+    kernel_version: String,
+    hostname: String,
+    uptime: Duration,
+    boot_time: DateTime<Local>,
+    users: Vec<String>,
+}
+
+/// Converts a unix timestamp to a local `DateTime`, without panicking on a DST-ambiguous moment.
+fn local_timestamp(unix_secs: i64) -> DateTime<Local> {
+    match Local.timestamp_opt(unix_secs, 0) {
+        chrono::LocalResult::Single(dt) => dt,
+        chrono::LocalResult::Ambiguous(earliest, _latest) => earliest,
+        chrono::LocalResult::None => Local.timestamp_opt(0, 0).earliest().unwrap(),
+    }
+}
+
+fn format_uptime(uptime: Duration) -> String {
+    let total_minutes = uptime.as_secs() / 60;
+    let days = total_minutes / (24 * 60);
+    let hours = (total_minutes / 60) % 24;
+    let minutes = total_minutes % 60;
+    format!("{}d {}h {}m", days, hours, minutes)
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct ProcessRow {
+    pid: u32,
+    name: String,
+    cpu_usage: f32,
+    memory: u64,
+    disk_read: u64,
+    disk_write: u64,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum ExportFormat {
+    Text,
+    Json,
+    Csv,
+}
+
+/// Formats a byte count with the largest whole unit it fits (KiB/MiB/GiB/TiB).
+fn human_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes as f64;
+    let mut unit_index = 0;
+    while value >= 1024.0 && unit_index < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit_index += 1;
+    }
+    format!("{:.2} {}", value, UNITS[unit_index])
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SortColumn {
+    Pid,
+    Name,
+    Cpu,
+    Mem,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct ProcessSorting {
+    column: SortColumn,
+    reverse: bool,
+}
+
+impl Default for ProcessSorting {
+    fn default() -> Self {
+        ProcessSorting {
+            column: SortColumn::Cpu,
+            reverse: true,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
 enum Message {
     Refresh,
-    CpuUsage, 
+    Refreshed(Box<SystemInformation>, Box<HostInfo>),
+    CpuUsage,
+    SortProcesses(SortColumn),
+    SetRefreshInterval(u64),
+    SearchInput(String),
+    ToggleRegex,
+    Export(ExportFormat),
+}
+
+/// Whether a process name should be kept under the current search filter.
+fn process_matches(name: &str, search_query: &str, use_regex: bool, search_regex: Option<&Regex>) -> bool {
+    if search_query.is_empty() {
+        return true;
+    }
+    if use_regex {
+        search_regex.is_none_or(|re| re.is_match(name))
+    } else {
+        name.to_lowercase().contains(&search_query.to_lowercase())
+    }
+}
+
+fn sort_processes(processes: &mut [ProcessRow], sorting: ProcessSorting) {
+    match sorting.column {
+        SortColumn::Pid => processes.sort_by_key(|p| p.pid),
+        SortColumn::Name => processes.sort_by(|a, b| a.name.cmp(&b.name)),
+        SortColumn::Cpu => processes.sort_by(|a, b| {
+            a.cpu_usage.partial_cmp(&b.cpu_usage).unwrap_or(std::cmp::Ordering::Equal)
+        }),
+        SortColumn::Mem => processes.sort_by_key(|p| p.memory),
+    }
+    if sorting.reverse {
+        processes.reverse();
+    }
+}
+
+/// Refreshes `system` in place and builds a snapshot from it. Runs on a background thread
+/// (see `refresh_command`) since `refresh_all()` can take a while with a few hundred
+/// processes and would otherwise stall the UI thread on every tick.
+fn gather_snapshot(system: &Mutex<System>, process_sorting: ProcessSorting) -> (SystemInformation, HostInfo) {
+    let mut sys = system.lock().unwrap();
+    sys.refresh_all();
+
+    let mut network_info = Vec::new();
+    for (name, data) in sys.networks() {
+        network_info.push((name.clone(), data.received(), data.transmitted()));
+    }
+
+    let mut processes: Vec<ProcessRow> = sys.processes().values().map(|process| {
+        let disk_usage = process.disk_usage();
+        ProcessRow {
+            pid: process.pid().as_u32(),
+            name: process.name().to_string(),
+            cpu_usage: process.cpu_usage(),
+            memory: process.memory(),
+            disk_read: disk_usage.read_bytes,
+            disk_write: disk_usage.written_bytes,
+        }
+    }).collect();
+    sort_processes(&mut processes, process_sorting);
+
+    let information = SystemInformation {
+        cpu_usages: sys.cpus().iter().map(|cpu| cpu.cpu_usage()).collect(),
+        used_memory: sys.used_memory(),
+        total_memory: sys.total_memory(),
+        used_swap: sys.used_swap(),
+        total_swap: sys.total_swap(),
+        disks: sys.disks().iter().map(|disk| (
+            disk.name().to_string_lossy().into_owned(),
+            disk.total_space(),
+            disk.total_space() - disk.available_space(),
+        )).collect(),
+        networks: network_info,
+        processes,
+        components: sys.components().iter().map(|component| (
+            component.label().to_string(),
+            component.temperature(),
+            component.critical(),
+        )).collect(),
+    };
+
+    let host_info = HostInfo {
+        os_name: sys.name().unwrap_or_else(|| String::from("unknown")),
+        os_version: sys.os_version().unwrap_or_else(|| String::from("unknown")),
+        kernel_version: sys.kernel_version().unwrap_or_else(|| String::from("unknown")),
+        hostname: sys.host_name().unwrap_or_else(|| String::from("unknown")),
+        uptime: Duration::from_secs(sys.uptime()),
+        boot_time: local_timestamp(sys.boot_time() as i64),
+        users: sys.users().iter().map(|user| user.name().to_string()).collect(),
+    };
+
+    (information, host_info)
+}
+
+/// Runs `gather_snapshot` on a plain OS thread and reports the result as `Message::Refreshed`,
+/// keeping the (potentially slow) sysinfo call off of the update/UI thread.
+fn refresh_command(system: Arc<Mutex<System>>, process_sorting: ProcessSorting) -> Command<Message> {
+    Command::perform(
+        async move {
+            let (sender, receiver) = iced::futures::channel::oneshot::channel();
+            std::thread::spawn(move || {
+                let snapshot = gather_snapshot(&system, process_sorting);
+                let _ = sender.send(snapshot);
+            });
+            receiver.await.expect("refresh thread panicked")
+        },
+        |(information, host_info)| Message::Refreshed(Box::new(information), Box::new(host_info)),
+    )
 }
 
-impl Sandbox for Task {
+impl Application for Task {
+    type Executor = iced::executor::Default;
     type Message = Message;
+    type Theme = Theme;
+    type Flags = ();
 
-    fn new() -> Self {
-        let mut app = Task::Loading;
-        app.update(Message::Refresh);
-        app
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        let system = Arc::new(Mutex::new(System::new_all()));
+        let command = refresh_command(system.clone(), ProcessSorting::default());
+        (Task { system, state: State::Loading }, command)
     }
 
     fn title(&self) -> String {
@@ -52,68 +344,184 @@ impl Sandbox for Task {
         Theme::Dark
     }
 
-    fn update(&mut self, message: Message) {
+    fn subscription(&self) -> Subscription<Message> {
+        match &self.state {
+            State::Loaded { refresh_interval_ms, .. } => {
+                iced::time::every(Duration::from_millis(*refresh_interval_ms)).map(|_| Message::Refresh)
+            }
+            State::Loading => Subscription::none(),
+        }
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
         match message {
             Message::Refresh => {
-                let mut sys = System::new_all();
-                sys.refresh_all();
+                let process_sorting = match &self.state {
+                    State::Loaded { process_sorting, .. } => *process_sorting,
+                    State::Loading => ProcessSorting::default(),
+                };
+                refresh_command(self.system.clone(), process_sorting)
+            }
+            Message::Refreshed(information, host_info) => {
+                let process_sorting = match &self.state {
+                    State::Loaded { process_sorting, .. } => *process_sorting,
+                    State::Loading => ProcessSorting::default(),
+                };
 
-                let mut network_info = Vec::new();
-                for (name, data) in sys.networks() {
-                    network_info.push((name.clone(), data.received(), data.transmitted()));
-                }
+                let mut history = match &mut self.state {
+                    State::Loaded { history, .. } => std::mem::take(history),
+                    State::Loading => Box::<MetricHistory>::default(),
+                };
+
+                let (search_query, use_regex, search_regex) = match &self.state {
+                    State::Loaded { search_query, use_regex, search_regex, .. } => {
+                        (search_query.clone(), *use_regex, search_regex.clone())
+                    }
+                    State::Loading => (String::new(), false, None),
+                };
 
-                let information = SystemInformation {
-                    cpu_usages: sys.cpus().iter().map(|cpu| cpu.cpu_usage()).collect(),
-                    used_memory: sys.used_memory(),
-                    total_memory: sys.total_memory(),
-                    used_swap: sys.used_swap(),
-                    total_swap: sys.total_swap(),
-                    disks: sys.disks().iter().map(|disk| (
-                        disk.name().to_string_lossy().into_owned(),
-                        disk.total_space(),
-                        disk.total_space() - disk.available_space(),
-                    )).collect(),
-                    networks: network_info,
+                let refresh_interval_ms = match &self.state {
+                    State::Loaded { refresh_interval_ms, .. } => *refresh_interval_ms,
+                    State::Loading => DEFAULT_REFRESH_INTERVAL_MS,
                 };
 
+                let now = Instant::now();
+                let cpu_total = if information.cpu_usages.is_empty() {
+                    0.0
+                } else {
+                    information.cpu_usages.iter().sum::<f32>() / information.cpu_usages.len() as f32
+                };
+                let memory_percent = (information.used_memory as f64 / information.total_memory as f64 * 100.0) as f32;
+                let net_rx: f32 = information.networks.iter().map(|(_, rx, _)| *rx as f32).sum();
+                let net_tx: f32 = information.networks.iter().map(|(_, _, tx)| *tx as f32).sum();
+                history.push(now, cpu_total, memory_percent, net_rx, net_tx);
 
                 if let Err(err) = file("system_info.txt", &information) {
                     println!("Error writing to file: {}", err);
                 }
 
-                *self = Self::Loaded {
+                self.state = State::Loaded {
                     information,
-                    show_cpu_usage: false, 
+                    show_cpu_usage: false,
+                    process_sorting,
+                    history,
+                    refresh_interval_ms,
+                    search_query,
+                    use_regex,
+                    search_regex,
+                    host_info,
                 };
+
+                Command::none()
             }
             Message::CpuUsage => {
-                if let Task::Loaded { ref mut show_cpu_usage, .. } = *self {
-                    *show_cpu_usage = !*show_cpu_usage; 
+                if let State::Loaded { ref mut show_cpu_usage, .. } = self.state {
+                    *show_cpu_usage = !*show_cpu_usage;
+                }
+                Command::none()
+            }
+            Message::SortProcesses(column) => {
+                if let State::Loaded { ref mut information, ref mut process_sorting, .. } = self.state {
+                    if process_sorting.column == column {
+                        process_sorting.reverse = !process_sorting.reverse;
+                    } else {
+                        process_sorting.column = column;
+                        process_sorting.reverse = false;
+                    }
+                    sort_processes(&mut information.processes, *process_sorting);
+                }
+                Command::none()
+            }
+            Message::SetRefreshInterval(ms) => {
+                if let State::Loaded { ref mut refresh_interval_ms, .. } = self.state {
+                    *refresh_interval_ms = ms;
+                }
+                Command::none()
+            }
+            Message::SearchInput(query) => {
+                if let State::Loaded { ref mut search_query, use_regex, ref mut search_regex, .. } = self.state {
+                    *search_query = query;
+                    if use_regex {
+                        if let Ok(re) = Regex::new(search_query) {
+                            *search_regex = Some(re);
+                        }
+                    }
                 }
+                Command::none()
+            }
+            Message::ToggleRegex => {
+                if let State::Loaded { ref search_query, ref mut use_regex, ref mut search_regex, .. } = self.state {
+                    *use_regex = !*use_regex;
+                    if *use_regex {
+                        if let Ok(re) = Regex::new(search_query) {
+                            *search_regex = Some(re);
+                        }
+                    }
+                }
+                Command::none()
+            }
+            Message::Export(format) => {
+                if let State::Loaded { ref information, ref history, .. } = self.state {
+                    let result = match format {
+                        ExportFormat::Text => file("system_info.txt", information),
+                        ExportFormat::Json => export_json("system_info.json", information),
+                        ExportFormat::Csv => export_csv("system_info.csv", history),
+                    };
+                    if let Err(err) = result {
+                        println!("Error exporting: {}", err);
+                    }
+                }
+                Command::none()
             }
         }
     }
 
-    fn view(&self) -> Element<Message> {
-        let content: Element<_> = match self {
-            Task::Loading => text("Loading...").size(40).into(),
-            Task::Loaded { information, show_cpu_usage } => {
+    fn view(&self) -> Element<'_, Message> {
+        let content: Element<_> = match &self.state {
+            State::Loading => text("Loading...").size(40).into(),
+            State::Loaded {
+                information,
+                show_cpu_usage,
+                process_sorting,
+                history,
+                refresh_interval_ms,
+                search_query,
+                use_regex,
+                search_regex,
+                host_info,
+            } => {
                 let memory_per = (information.used_memory as f64 / information.total_memory as f64) * 100.0;
                 let swap_per = (information.used_swap as f64 / information.total_swap as f64) * 100.0;
 
                 let mut column_content = column![
                     text("                        System Monitor").size(30),
                     text(format!(
-                        "   Memory: used {:.2} TB / total {:.2} TB ({:.2}%)",
-                        information.used_memory as f64 / 1_048_576.0,
-                        information.total_memory as f64 / 1_048_576.0,
+                        "   {} {} on {} ({})",
+                        host_info.os_name,
+                        host_info.os_version,
+                        host_info.hostname,
+                        host_info.kernel_version,
+                    )).size(16),
+                    text(format!(
+                        "   Uptime: {}  |  Boot time: {}  |  Users: {}",
+                        format_uptime(host_info.uptime),
+                        host_info.boot_time.format("%Y-%m-%d %H:%M"),
+                        if host_info.users.is_empty() {
+                            String::from("none")
+                        } else {
+                            host_info.users.join(", ")
+                        },
+                    )).size(14),
+                    text(format!(
+                        "   Memory: used {} / total {} ({:.2}%)",
+                        human_bytes(information.used_memory),
+                        human_bytes(information.total_memory),
                         memory_per
                     )),
                     text(format!(
-                        "   Swap: used {:.2} TB / total {:.2} TB ({:.2}%)",
-                        information.used_swap as f64 / 1_048_576.0,
-                        information.total_swap as f64 / 1_048_576.0,
+                        "   Swap: used {} / total {} ({:.2}%)",
+                        human_bytes(information.used_swap),
+                        human_bytes(information.total_swap),
                         swap_per
                     )),
  
@@ -133,31 +541,141 @@ impl Sandbox for Task {
                     }
                 }
 
+                column_content = column_content.push(text("    CPU history (60s):").size(20));
+                column_content = column_content.push(
+                    Canvas::new(LineChart { series: &history.cpu_total })
+                        .width(Length::Fixed(400.0))
+                        .height(Length::Fixed(80.0))
+                );
+                column_content = column_content.push(text("    Memory history (60s):").size(20));
+                column_content = column_content.push(
+                    Canvas::new(LineChart { series: &history.memory_percent })
+                        .width(Length::Fixed(400.0))
+                        .height(Length::Fixed(80.0))
+                );
+                column_content = column_content.push(text("    Network rx history (60s):").size(20));
+                column_content = column_content.push(
+                    Canvas::new(LineChart { series: &history.net_rx })
+                        .width(Length::Fixed(400.0))
+                        .height(Length::Fixed(80.0))
+                );
+                column_content = column_content.push(text("    Network tx history (60s):").size(20));
+                column_content = column_content.push(
+                    Canvas::new(LineChart { series: &history.net_tx })
+                        .width(Length::Fixed(400.0))
+                        .height(Length::Fixed(80.0))
+                );
+
                 column_content = column_content.push(text("    Disk usage:").size(20));
                 for (name, total, used) in &information.disks {
                     let disk_usage_percentage = (*used as f64 / *total as f64) * 100.0;
                     column_content = column_content.push(text(format!(
-                        "           {}: {:.2} GB used / {:.2} GB total ({:.2}%)",
+                        "           {}: {} used / {} total ({:.2}%)",
                         name,
-                        *used as f64 / 1_073_741_824.0,
-                        *total as f64 / 1_073_741_824.0,
+                        human_bytes(*used),
+                        human_bytes(*total),
                         disk_usage_percentage
                     )));
                 }
-                
+
 
                 column_content = column_content.push(text("    Network usage:").size(20));
                 for (name, received, transmitted) in &information.networks {
                     column_content = column_content.push(text(format!(
-                        "           {}: received {} KB / transmitted {} KB",
+                        "           {}: received {} / transmitted {}",
                         name,
-                        *received / 1024,
-                        *transmitted / 1024
+                        human_bytes(*received),
+                        human_bytes(*transmitted)
                     )));
                 }
 
+                column_content = column_content.push(text("    Temperatures:").size(20));
+                for (label, temperature, critical) in &information.components {
+                    let line = text(format!(
+                        "           {}: {:.1}°C{}",
+                        label,
+                        temperature,
+                        critical.map_or(String::new(), |c| format!(" (critical {:.1}°C)", c))
+                    ));
+                    let is_hot = critical.is_some_and(|c| *temperature >= c * 0.9);
+                    column_content = column_content.push(if is_hot {
+                        line.style(iced::theme::Text::Color(Color::from_rgb(1.0, 0.2, 0.2)))
+                    } else {
+                        line
+                    });
+                }
+
+                column_content = column_content.push(text("    Processes:").size(20));
+                column_content = column_content.push(
+                    row![
+                        button("PID").on_press(Message::SortProcesses(SortColumn::Pid)),
+                        button("Name").on_press(Message::SortProcesses(SortColumn::Name)),
+                        button("CPU %").on_press(Message::SortProcesses(SortColumn::Cpu)),
+                        button("Memory").on_press(Message::SortProcesses(SortColumn::Mem)),
+                    ]
+                    .spacing(10)
+                );
+                let sort_indicator = if process_sorting.reverse { "desc" } else { "asc" };
+                column_content = column_content.push(
+                    text(format!("    (sorted by {:?}, {})", process_sorting.column, sort_indicator)).size(14)
+                );
+
+                column_content = column_content.push(
+                    row![
+                        text_input("Search processes...", search_query)
+                            .on_input(Message::SearchInput)
+                            .width(Length::Fixed(250.0)),
+                        button(if *use_regex { "Regex: on" } else { "Regex: off" })
+                            .on_press(Message::ToggleRegex),
+                    ]
+                    .spacing(10)
+                );
+
+                let mut process_table = column![].spacing(2);
+                for proc in information.processes.iter().filter(|proc| {
+                    process_matches(&proc.name, search_query, *use_regex, search_regex.as_ref())
+                }) {
+                    process_table = process_table.push(text(format!(
+                        "           {:>7}  {:<24}  {:>6.2}%  {:>10}  r {:>10}  w {:>10}",
+                        proc.pid,
+                        proc.name,
+                        proc.cpu_usage,
+                        human_bytes(proc.memory),
+                        human_bytes(proc.disk_read),
+                        human_bytes(proc.disk_write),
+                    )).size(14));
+                }
+                let process_scroll = iced::widget::scrollable(process_table).height(iced::Length::Fixed(200.0));
+                column_content = column_content.push(process_scroll);
+
                 column_content = column_content.push(button("Refresh").on_press(Message::Refresh));
 
+                column_content = column_content.push(text(format!(
+                    "    Auto-refresh every {} ms:", refresh_interval_ms
+                )).size(14));
+                let mut interval_row = row![].spacing(10);
+                for ms in REFRESH_INTERVALS_MS {
+                    interval_row = interval_row.push(
+                        button(text(if ms >= 1_000 {
+                            format!("{}s", ms / 1_000)
+                        } else {
+                            format!("{}ms", ms)
+                        }))
+                        .on_press(Message::SetRefreshInterval(ms))
+                    );
+                }
+                column_content = column_content.push(interval_row);
+
+                column_content = column_content.push(text("    Export:").size(14));
+                column_content = column_content.push(
+                    row![
+                        button("Text").on_press(Message::Export(ExportFormat::Text)),
+                        button("JSON").on_press(Message::Export(ExportFormat::Json)),
+                        button("CSV").on_press(Message::Export(ExportFormat::Csv)),
+                    ]
+                    .spacing(10)
+                );
+
                 column_content.into()
             }
         };
@@ -167,7 +685,7 @@ impl Sandbox for Task {
 }
 
 fn file(path: &str, information: &SystemInformation) -> Result<(), Error> {
-    let mut file = OpenOptions::new().write(true).create(true).append(true).open(path)?;
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
     let memory_usage_percentage = (information.used_memory as f64 / information.total_memory as f64) * 100.0;
     let swap_usage_percentage = (information.used_swap as f64 / information.total_swap as f64) * 100.0;
 
@@ -183,10 +701,10 @@ fn file(path: &str, information: &SystemInformation) -> Result<(), Error> {
     .map(|(name, total, used)| {
         let disk_usage_percentage = (*used as f64 / *total as f64) * 100.0;
         format!(
-            "{}: {:.2} GB used / {:.2} GB total ({:.2}%)",
+            "{}: {} used / {} total ({:.2}%)",
             name,
-            *used as f64 / 1_073_741_824.0,
-            *total as f64 / 1_073_741_824.0,
+            human_bytes(*used),
+            human_bytes(*total),
             disk_usage_percentage
         )
     }).collect::<Vec<String>>().join(", ");
@@ -194,21 +712,21 @@ fn file(path: &str, information: &SystemInformation) -> Result<(), Error> {
     let network_usage = information.networks
         .iter().map(|(name, received, transmitted)| {
             format!(
-                "{}: received {} KB / transmitted {} KB",
+                "{}: received {} / transmitted {}",
                 name,
-                *received / 1024,
-                *transmitted / 1024
+                human_bytes(*received),
+                human_bytes(*transmitted)
             )
         }).collect::<Vec<String>>().join(", ");
 
     let data = format!(
-        "Time: {}\nMemory: used {:.2} TB / total {:.2} TB ({:.2}%)\nSwap: used {:.2} TB / total {:.2} TB ({:.2}%)\nCPU Usage: {}\nDisk Usage: {}\nNetwork Usage: {}\n\n",
+        "Time: {}\nMemory: used {} / total {} ({:.2}%)\nSwap: used {} / total {} ({:.2}%)\nCPU Usage: {}\nDisk Usage: {}\nNetwork Usage: {}\n\n",
         formatted_time,
-        information.used_memory as f64 / 1_048_576.0,
-        information.total_memory as f64 / 1_048_576.0,
+        human_bytes(information.used_memory),
+        human_bytes(information.total_memory),
         memory_usage_percentage,
-        information.used_swap as f64 / 1_048_576.0,
-        information.total_swap as f64 / 1_048_576.0,
+        human_bytes(information.used_swap),
+        human_bytes(information.total_swap),
         swap_usage_percentage,
         cpu_usage,
         disk_usage,
@@ -217,4 +735,193 @@ fn file(path: &str, information: &SystemInformation) -> Result<(), Error> {
 
     file.write_all(data.as_bytes())?;
     Ok(())
+}
+
+fn create_export_file(path: &str) -> Result<std::fs::File, Error> {
+    OpenOptions::new().write(true).create(true).truncate(true).open(path)
+}
+
+fn export_json(path: &str, information: &SystemInformation) -> Result<(), Error> {
+    let mut file = create_export_file(path)?;
+    let json = serde_json::to_string_pretty(information)
+        .map_err(Error::other)?;
+    file.write_all(json.as_bytes())?;
+    Ok(())
+}
+
+/// Formats one CSV row for a history sample, `seconds_ago` being its age relative to `newest`.
+fn csv_row(newest: Instant, sample_time: Instant, cpu_total: f32, memory_percent: f32, net_rx: f32, net_tx: f32) -> String {
+    let seconds_ago = newest.duration_since(sample_time).as_secs_f32();
+    format!(
+        "{:.1},{:.2},{:.2},{:.2},{:.2}",
+        seconds_ago, cpu_total, memory_percent, net_rx, net_tx
+    )
+}
+
+fn export_csv(path: &str, history: &MetricHistory) -> Result<(), Error> {
+    let mut file = create_export_file(path)?;
+    writeln!(file, "seconds_ago,cpu_total,memory_percent,net_rx,net_tx")?;
+
+    let newest = history.cpu_total.back().map(|(t, _)| *t).unwrap_or_else(Instant::now);
+    for i in 0..history.cpu_total.len() {
+        let (sample_time, cpu_total) = history.cpu_total[i];
+        let memory_percent = history.memory_percent.get(i).map_or(0.0, |(_, v)| *v);
+        let net_rx = history.net_rx.get(i).map_or(0.0, |(_, v)| *v);
+        let net_tx = history.net_tx.get(i).map_or(0.0, |(_, v)| *v);
+        writeln!(file, "{}", csv_row(newest, sample_time, cpu_total, memory_percent, net_rx, net_tx))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn human_bytes_stays_under_a_kib_in_bytes() {
+        assert_eq!(human_bytes(1023), "1023.00 B");
+    }
+
+    #[test]
+    fn human_bytes_rolls_over_at_1024() {
+        assert_eq!(human_bytes(1024), "1.00 KiB");
+    }
+
+    #[test]
+    fn human_bytes_picks_the_largest_fitting_unit() {
+        assert_eq!(human_bytes(1024 * 1024), "1.00 MiB");
+        assert_eq!(human_bytes(1024 * 1024 * 1024), "1.00 GiB");
+        assert_eq!(human_bytes(1024u64.pow(4)), "1.00 TiB");
+    }
+
+    #[test]
+    fn human_bytes_caps_out_at_tib() {
+        assert_eq!(human_bytes(1024u64.pow(5)), "1024.00 TiB");
+    }
+
+    #[test]
+    fn format_uptime_breaks_down_days_hours_minutes() {
+        assert_eq!(format_uptime(Duration::from_secs(90_061)), "1d 1h 1m");
+    }
+
+    #[test]
+    fn process_matches_simple_mode_is_case_insensitive_substring() {
+        assert!(process_matches("Firefox", "fire", false, None));
+        assert!(!process_matches("Firefox", "chrome", false, None));
+    }
+
+    #[test]
+    fn process_matches_regex_mode_uses_compiled_pattern() {
+        let re = Regex::new("^fire.*$").unwrap();
+        assert!(process_matches("firefox", "^fire.*$", true, Some(&re)));
+        assert!(!process_matches("chrome", "^fire.*$", true, Some(&re)));
+    }
+
+    #[test]
+    fn process_matches_regex_mode_without_compiled_pattern_keeps_rows() {
+        assert!(process_matches("anything", "[", true, None));
+    }
+
+    #[test]
+    fn csv_row_reports_sample_age_and_values() {
+        let newest = Instant::now();
+        let row = csv_row(newest, newest, 12.5, 40.0, 100.0, 200.0);
+        assert_eq!(row, "0.0,12.50,40.00,100.00,200.00");
+    }
+
+    #[test]
+    fn metric_history_evicts_samples_older_than_retention() {
+        let mut history = MetricHistory::default();
+        let t0 = Instant::now();
+        history.push(t0, 1.0, 1.0, 1.0, 1.0);
+        history.push(t0 + Duration::from_secs(30), 2.0, 2.0, 2.0, 2.0);
+        history.push(t0 + Duration::from_secs(70), 3.0, 3.0, 3.0, 3.0);
+
+        assert_eq!(history.cpu_total.len(), 2);
+        assert_eq!(history.memory_percent.len(), 2);
+        assert_eq!(history.net_rx.len(), 2);
+        assert_eq!(history.net_tx.len(), 2);
+        assert_eq!(history.cpu_total.front().unwrap().1, 2.0);
+    }
+
+    #[test]
+    fn refresh_records_nonzero_cpu_usage_after_two_refreshes_under_load() {
+        use std::io::{Read, Write};
+        use std::net::{TcpListener, TcpStream};
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        let system = Arc::new(Mutex::new(System::new_all()));
+        let mut app = Task { system: system.clone(), state: State::Loading };
+        // Drive the background refresh to completion synchronously: the first snapshot
+        // establishes the baseline sysinfo deltas against, so it reports ~0 by design.
+        let (information, host_info) = gather_snapshot(&system, ProcessSorting::default());
+        let _ = app.update(Message::Refreshed(Box::new(information), Box::new(host_info)));
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let cpu_stop = stop.clone();
+        let cpu_load = std::thread::spawn(move || {
+            let mut acc = 0u64;
+            while !cpu_stop.load(Ordering::Relaxed) {
+                acc = acc.wrapping_add(1);
+            }
+            acc
+        });
+
+        // Also push a burst of loopback traffic so net_rx/net_tx have something to observe,
+        // though byte counters are host-dependent and not strictly asserted on below.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            if let Ok((mut stream, _)) = listener.accept() {
+                let mut buf = [0u8; 4096];
+                while stream.read(&mut buf).unwrap_or(0) > 0 {}
+            }
+        });
+        {
+            let mut client = TcpStream::connect(addr).unwrap();
+            let payload = [0u8; 4096];
+            let deadline = Instant::now() + Duration::from_millis(300);
+            while Instant::now() < deadline && client.write_all(&payload).is_ok() {}
+        }
+
+        let (information, host_info) = gather_snapshot(&system, ProcessSorting::default());
+        let _ = app.update(Message::Refreshed(Box::new(information), Box::new(host_info)));
+        stop.store(true, Ordering::Relaxed);
+        let _ = cpu_load.join();
+        let _ = server.join();
+
+        match &app.state {
+            State::Loaded { history, .. } => {
+                assert!(history.cpu_total.back().unwrap().1 > 0.0);
+            }
+            State::Loading => panic!("expected Loaded state after two refreshes"),
+        }
+    }
+
+    fn process_row(pid: u32, cpu_usage: f32) -> ProcessRow {
+        ProcessRow {
+            pid,
+            name: format!("proc-{pid}"),
+            cpu_usage,
+            memory: 0,
+            disk_read: 0,
+            disk_write: 0,
+        }
+    }
+
+    #[test]
+    fn sort_processes_by_cpu_does_not_panic_on_nan() {
+        let mut processes = vec![process_row(1, f32::NAN), process_row(2, 5.0), process_row(3, 1.0)];
+        sort_processes(&mut processes, ProcessSorting { column: SortColumn::Cpu, reverse: false });
+        assert_eq!(processes.len(), 3);
+    }
+
+    #[test]
+    fn sort_processes_by_pid_reverse_descends() {
+        let mut processes = vec![process_row(1, 0.0), process_row(3, 0.0), process_row(2, 0.0)];
+        sort_processes(&mut processes, ProcessSorting { column: SortColumn::Pid, reverse: true });
+        assert_eq!(processes.iter().map(|p| p.pid).collect::<Vec<_>>(), vec![3, 2, 1]);
+    }
 }
\ No newline at end of file